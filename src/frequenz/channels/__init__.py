@@ -18,6 +18,11 @@ Channels:
 * [Broadcast][frequenz.channels.Broadcast]: A channel to broadcast messages
   from multiple senders to multiple receivers. Each message sent through any of
   the senders is received by all of the receivers.
+  [BroadcastReceiver][frequenz.channels.BroadcastReceiver] is the type of its
+  receivers.
+
+* [OneshotChannel][frequenz.channels.OneshotChannel]: A channel to send a single
+  message from one sender to one receiver.
 
 Other base classes:
 
@@ -32,6 +37,9 @@ Other base classes:
 
 Utilities:
 
+* [LatestValueCache][frequenz.channels.LatestValueCache]: A cache that keeps
+  the latest message received by a receiver, discarding the older ones.
+
 * [util][frequenz.channels.util]: A module with utilities, like special
   receivers that implement timers, file watchers, merge receivers, or wait for
   messages in multiple channels.
@@ -49,14 +57,19 @@ from . import util
 from ._anycast import Anycast
 from ._base_classes import ChannelClosedError, ChannelError, Peekable, Receiver, Sender
 from ._bidirectional import Bidirectional
-from ._broadcast import Broadcast
+from ._broadcast import Broadcast, BroadcastReceiver
+from ._latest_value_cache import LatestValueCache
+from ._oneshot import OneshotChannel
 
 __all__ = [
     "Anycast",
     "Bidirectional",
     "Broadcast",
+    "BroadcastReceiver",
     "ChannelClosedError",
     "ChannelError",
+    "LatestValueCache",
+    "OneshotChannel",
     "Peekable",
     "Receiver",
     "Sender",
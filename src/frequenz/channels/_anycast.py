@@ -6,7 +6,7 @@
 from __future__ import annotations
 
 import logging
-from asyncio import Condition
+from asyncio import CancelledError, Condition
 from collections import deque
 from typing import Generic, TypeVar
 
@@ -14,13 +14,15 @@ from typing_extensions import override
 
 from ._exceptions import ChannelClosedError
 from ._generic import ChannelMessageT
+from ._not_picklable import NotPicklable
+from ._ready_waker import ReadyWaker
 from ._receiver import Receiver, ReceiverStoppedError
 from ._sender import Sender, SenderError
 
 _logger = logging.getLogger(__name__)
 
 
-class Anycast(Generic[ChannelMessageT]):
+class Anycast(NotPicklable, Generic[ChannelMessageT]):
     """A channel that delivers each message to exactly one receiver.
 
     # Description
@@ -206,7 +208,13 @@ class Anycast(Generic[ChannelMessageT]):
             limit: The size of the internal buffer in number of messages.  If the buffer
                 is full, then the senders will block until the receivers consume the
                 messages in the buffer.
+
+        Raises:
+            ValueError: If `limit` is not a positive number.
         """
+        if limit < 1:
+            raise ValueError(f"The channel limit must be at least 1, not {limit}")
+
         self._name: str = name
         """The name of the channel.
 
@@ -266,16 +274,35 @@ class Anycast(Generic[ChannelMessageT]):
         assert maxlen is not None
         return maxlen
 
+    def __len__(self) -> int:
+        """Return the number of messages waiting in the channel's buffer.
+
+        Returns:
+            Number of messages in the channel's buffer, which have not been received by
+                any receiver yet.
+        """
+        return len(self._deque)
+
+    def __bool__(self) -> bool:
+        """Return `True`, even if the channel's buffer is empty.
+
+        Without this, `__len__` would make channels with an empty buffer falsy.
+
+        Returns:
+            Always `True`.
+        """
+        return True
+
     async def close(self) -> None:
         """Close the channel.
 
         Any further attempts to [send()][frequenz.channels.Sender.send] data
-        will return `False`.
+        will raise a [`SenderError`][frequenz.channels.SenderError].
 
         Receivers will still be able to drain the pending messages on the channel,
         but after that, subsequent
-        [receive()][frequenz.channels.Receiver.receive] calls will return `None`
-        immediately.
+        [receive()][frequenz.channels.Receiver.receive] calls will raise a
+        [`ReceiverStoppedError`][frequenz.channels.ReceiverStoppedError].
         """
         self._closed = True
         async with self._send_cv:
@@ -352,7 +379,15 @@ class _Sender(Sender[_T]):
             )
             while len(self._channel._deque) == self._channel._deque.maxlen:
                 async with self._channel._send_cv:
-                    await self._channel._send_cv.wait()
+                    try:
+                        await self._channel._send_cv.wait()
+                    except CancelledError:
+                        # Receivers only wake up one sender, so if this one was woken
+                        # up because there is room, pass it on to the next waiting
+                        # sender.
+                        if len(self._channel._deque) < self._channel._deque.maxlen:
+                            self._channel._send_cv.notify(1)
+                        raise
             _logger.info(
                 "Anycast channel [%s] has space again, resuming the blocked sender",
                 self,
@@ -394,6 +429,11 @@ class _Receiver(Receiver[_T]):
         self._closed: bool = False
         """Whether the receiver is closed."""
 
+        self._ready_waker: ReadyWaker = ReadyWaker(
+            channel._recv_cv  # pylint: disable=protected-access
+        )
+        """Wakes up a pending `ready()` call when this receiver is closed."""
+
         self._next: _T | type[_Empty] = _Empty
 
     @override
@@ -412,12 +452,30 @@ class _Receiver(Receiver[_T]):
         if self._next is not _Empty:
             return True
 
+        # a closed receiver must not take any more messages from the channel.
+        if self._closed:
+            return False
+
         # pylint: disable=protected-access
         while len(self._channel._deque) == 0:
-            if self._channel._closed:
+            if self._channel._closed or self._closed:
                 return False
             async with self._channel._recv_cv:
-                await self._channel._recv_cv.wait()
+                with self._ready_waker.waiting():
+                    try:
+                        await self._channel._recv_cv.wait()
+                    except CancelledError:
+                        # Senders only wake up one receiver, so if this one was woken
+                        # up by a message, pass it on to the next waiting receiver.
+                        if self._channel._deque:
+                            self._channel._recv_cv.notify(1)
+                        raise
+        if self._closed:
+            # The receiver was closed while waiting, leave the message to the other
+            # receivers.
+            async with self._channel._recv_cv:
+                self._channel._recv_cv.notify(1)
+            return False
         self._next = self._channel._deque.popleft()
         async with self._channel._send_cv:
             self._channel._send_cv.notify(1)
@@ -457,8 +515,12 @@ class _Receiver(Receiver[_T]):
         """Close this receiver.
 
         After closing, the receiver will not be able to receive any more messages.
+        Unlike broadcast receivers, anycast receivers don't have their own buffer, so
+        messages that are still in the channel's buffer are left to the other
+        receivers.
         """
         self._closed = True
+        self._ready_waker.wake_up()
 
     def __str__(self) -> str:
         """Return a string representation of this receiver."""
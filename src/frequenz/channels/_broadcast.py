@@ -15,13 +15,15 @@ from typing_extensions import override
 
 from ._exceptions import ChannelClosedError
 from ._generic import ChannelMessageT
+from ._not_picklable import NotPicklable
+from ._ready_waker import ReadyWaker
 from ._receiver import Receiver, ReceiverStoppedError
 from ._sender import Sender, SenderError
 
 _logger = logging.getLogger(__name__)
 
 
-class Broadcast(Generic[ChannelMessageT]):
+class Broadcast(NotPicklable, Generic[ChannelMessageT]):
     """A channel that deliver all messages to all receivers.
 
     # Description
@@ -208,7 +210,7 @@ class Broadcast(Generic[ChannelMessageT]):
         """The condition to wait for data in the channel's buffer."""
 
         self._receivers: dict[
-            int, weakref.ReferenceType[_Receiver[ChannelMessageT]]
+            int, weakref.ReferenceType[BroadcastReceiver[ChannelMessageT]]
         ] = {}
         """The receivers attached to the channel, indexed by their hash()."""
 
@@ -248,16 +250,24 @@ class Broadcast(Generic[ChannelMessageT]):
         """
         return self._closed
 
+    @property
+    def receiver_count(self) -> int:
+        """The number of receivers currently attached to this channel.
+
+        Receivers that were closed or garbage collected are not counted.
+        """
+        return sum(1 for recv_ref in self._receivers.values() if recv_ref() is not None)
+
     async def close(self) -> None:
         """Close this channel.
 
         Any further attempts to [send()][frequenz.channels.Sender.send] data
-        will return `False`.
+        will raise a [`SenderError`][frequenz.channels.SenderError].
 
         Receivers will still be able to drain the pending items on their queues,
         but after that, subsequent
-        [receive()][frequenz.channels.Receiver.receive] calls will return `None`
-        immediately.
+        [receive()][frequenz.channels.Receiver.receive] calls will raise a
+        [`ReceiverStoppedError`][frequenz.channels.ReceiverStoppedError].
         """
         self._latest = None
         self._closed = True
@@ -270,7 +280,7 @@ class Broadcast(Generic[ChannelMessageT]):
 
     def new_receiver(
         self, *, name: str | None = None, limit: int = 50
-    ) -> Receiver[ChannelMessageT]:
+    ) -> BroadcastReceiver[ChannelMessageT]:
         """Return a new receiver attached to this channel.
 
         Broadcast receivers have their own buffer, and when messages are not
@@ -283,11 +293,18 @@ class Broadcast(Generic[ChannelMessageT]):
 
         Returns:
             A new receiver attached to this channel.
+
+        Raises:
+            ValueError: If `limit` is not a positive number.
         """
-        recv: _Receiver[ChannelMessageT] = _Receiver(self, name=name, limit=limit)
+        if limit < 1:
+            raise ValueError(f"The receiver limit must be at least 1, not {limit}")
+        recv: BroadcastReceiver[ChannelMessageT] = BroadcastReceiver(
+            self, name=name, limit=limit
+        )
         self._receivers[hash(recv)] = weakref.ref(recv)
         if self.resend_latest and self._latest is not None:
-            recv.enqueue(self._latest)
+            recv._enqueue(self._latest)  # pylint: disable=protected-access
         return recv
 
     def __str__(self) -> str:
@@ -300,7 +317,7 @@ class Broadcast(Generic[ChannelMessageT]):
             f"{type(self).__name__}(name={self._name!r}, "
             f"resend_latest={self.resend_latest!r}):<"
             f"latest={self._latest!r}, "
-            f"receivers={len(self._receivers)!r}, "
+            f"receivers={self.receiver_count!r}, "
             f"closed={self._closed!r}>"
         )
 
@@ -349,7 +366,7 @@ class _Sender(Sender[_T]):
             if recv is None:
                 stale_refs.append(_hash)
                 continue
-            recv.enqueue(message)
+            recv._enqueue(message)
         for _hash in stale_refs:
             del self._channel._receivers[_hash]
         async with self._channel._recv_cv:
@@ -365,9 +382,14 @@ class _Sender(Sender[_T]):
         return f"{type(self).__name__}({self._channel!r})"
 
 
-class _Receiver(Receiver[_T]):
+class BroadcastReceiver(Receiver[_T]):
     """A receiver to receive messages from the broadcast channel.
 
+    Besides the [`Receiver`][frequenz.channels.Receiver] interface, broadcast
+    receivers report how many messages are waiting in their buffer with `len()`,
+    and the size of the buffer with the
+    [`limit`][frequenz.channels.BroadcastReceiver.limit] property.
+
     Should not be created directly, but through the
     [Broadcast.new_receiver()][frequenz.channels.Broadcast.new_receiver]
     method.
@@ -406,7 +428,12 @@ class _Receiver(Receiver[_T]):
         self._closed: bool = False
         """Whether the receiver is closed."""
 
-    def enqueue(self, message: _T, /) -> None:
+        self._ready_waker: ReadyWaker = ReadyWaker(
+            channel._recv_cv  # pylint: disable=protected-access
+        )
+        """Wakes up a pending `ready()` call when this receiver is closed."""
+
+    def _enqueue(self, message: _T, /) -> None:
         """Put a message into this receiver's queue.
 
         To be called by broadcast senders.  If the receiver's queue is already
@@ -424,6 +451,17 @@ class _Receiver(Receiver[_T]):
             )
         self._q.append(message)
 
+    @property
+    def limit(self) -> int:
+        """The maximum number of messages that can be stored in the receiver's buffer.
+
+        If the length of the receiver's buffer reaches the limit, then the oldest
+        message is dropped to make room for every new incoming message.
+        """
+        maxlen = self._q.maxlen
+        assert maxlen is not None
+        return maxlen
+
     def __len__(self) -> int:
         """Return the number of unconsumed messages in the broadcast receiver.
 
@@ -454,10 +492,11 @@ class _Receiver(Receiver[_T]):
         # consumed, then we return immediately.
         # pylint: disable=protected-access
         while len(self._q) == 0:
-            if self._channel._closed:
+            if self._channel._closed or self._closed:
                 return False
             async with self._channel._recv_cv:
-                await self._channel._recv_cv.wait()
+                with self._ready_waker.waiting():
+                    await self._channel._recv_cv.wait()
         return True
         # pylint: enable=protected-access
 
@@ -474,6 +513,9 @@ class _Receiver(Receiver[_T]):
         if not self._q and self._channel._closed:  # pylint: disable=protected-access
             raise ReceiverStoppedError(self) from ChannelClosedError(self._channel)
 
+        if not self._q and self._closed:
+            raise ReceiverStoppedError(self)
+
         assert self._q, "`consume()` must be preceded by a call to `ready()`"
         return self._q.popleft()
 
@@ -489,6 +531,7 @@ class _Receiver(Receiver[_T]):
         self._channel._receivers.pop(  # pylint: disable=protected-access
             hash(self), None
         )
+        self._ready_waker.wake_up()
 
     def __str__(self) -> str:
         """Return a string representation of this receiver."""
@@ -496,9 +539,7 @@ class _Receiver(Receiver[_T]):
 
     def __repr__(self) -> str:
         """Return a string representation of this receiver."""
-        limit = self._q.maxlen
-        assert limit is not None
         return (
-            f"{type(self).__name__}(name={self._name!r}, limit={limit!r}, "
+            f"{type(self).__name__}(name={self._name!r}, limit={self.limit!r}, "
             f"{self._channel!r}):<id={id(self)!r}, used={len(self._q)!r}>"
         )
@@ -0,0 +1,163 @@
+# License: MIT
+# Copyright © 2024 Frequenz Energy-as-a-Service GmbH
+
+"""A class that caches the latest value in a receiver.
+
+Sometimes only the most recent message received by a receiver is relevant, and the
+messages that were received before are just stale.
+
+A [`LatestValueCache`][frequenz.channels.LatestValueCache] takes a receiver and
+continuously consumes its messages in a background task, keeping only the latest
+one, so it can be retrieved at any time with
+[`get()`][frequenz.channels.LatestValueCache.get]:
+
+```python show_lines="8:"
+import asyncio
+
+from frequenz.channels import Broadcast, LatestValueCache
+
+channel = Broadcast[int](name="lvc_test")
+
+cache = LatestValueCache(channel.new_receiver())
+sender = channel.new_sender()
+
+assert not cache.has_value()
+
+await sender.send(5)
+assert await cache.wait_for_value() == 5
+
+await sender.send(6)
+await sender.send(7)
+await asyncio.sleep(0)  # let the cache consume the new messages
+assert cache.get() == 7
+```
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+from typing import Generic
+
+from ._generic import ReceiverMessageT_co
+from ._receiver import Receiver, ReceiverStoppedError
+
+_logger = logging.getLogger(__name__)
+
+
+class _Sentinel:
+    """A sentinel to denote that no value has been received yet."""
+
+    def __str__(self) -> str:
+        """Return a string representation of this sentinel."""
+        return "<no value received yet>"
+
+
+class LatestValueCache(Generic[ReceiverMessageT_co]):
+    """A cache that stores the latest value in a receiver.
+
+    It provides a way to look up the latest value in a stream without consuming the
+    stream, which is useful when only the newest message is relevant, as all the
+    older messages are discarded as soon as a new one arrives.
+    """
+
+    def __init__(
+        self, receiver: Receiver[ReceiverMessageT_co], *, unique_id: str | None = None
+    ) -> None:
+        """Create a new cache.
+
+        Args:
+            receiver: The receiver to cache.
+            unique_id: A string to help uniquely identify this instance. If not
+                provided, a unique identifier will be generated from the object's
+                [`id()`][id]. It is used mostly for debugging purposes.
+        """
+        self._receiver = receiver
+        self._unique_id: str = hex(id(self)) if unique_id is None else unique_id
+        self._latest_value: ReceiverMessageT_co | _Sentinel = _Sentinel()
+        self._got_value: asyncio.Event = asyncio.Event()
+        self._error: Exception | None = None
+        self._task = asyncio.create_task(
+            self._run(), name=f"LatestValueCache«{self._unique_id}»"
+        )
+
+    @property
+    def unique_id(self) -> str:
+        """The unique identifier of this instance."""
+        return self._unique_id
+
+    def get(self) -> ReceiverMessageT_co:
+        """Return the latest value that has been received.
+
+        This raises a `ValueError` if no value has been received yet. Use `has_value`
+        to check whether a value has been received yet, before trying to access the
+        value, to avoid the exception.
+
+        Returns:
+            The latest value that has been received.
+
+        Raises:
+            ValueError: If no value has been received yet.
+        """
+        if isinstance(self._latest_value, _Sentinel):
+            raise ValueError("No value has been received yet.")
+        return self._latest_value
+
+    def has_value(self) -> bool:
+        """Check whether a value has been received yet.
+
+        Returns:
+            `True` if a value has been received, `False` otherwise.
+        """
+        return not isinstance(self._latest_value, _Sentinel)
+
+    async def wait_for_value(self) -> ReceiverMessageT_co:
+        """Wait until a value has been received and return the latest one.
+
+        If a value was already received, it is returned immediately.
+
+        Returns:
+            The latest value that has been received.
+
+        Raises:
+            ReceiverStoppedError: If the receiver stopped, or the cache was stopped,
+                before any value was received. If the receiver stopped because it
+                raised an exception, that exception is set as the cause.
+        """
+        await self._got_value.wait()
+        if isinstance(self._latest_value, _Sentinel):
+            raise ReceiverStoppedError(self._receiver) from self._error
+        return self._latest_value
+
+    async def _run(self) -> None:
+        try:
+            async for value in self._receiver:
+                self._latest_value = value
+                self._got_value.set()
+        except Exception as exc:  # pylint: disable=broad-exception-caught
+            _logger.exception("%r: The receiver failed, stopping the cache.", self)
+            self._error = exc
+        finally:
+            self._receiver.close()
+            # Wake up anyone waiting for a value, so they don't wait forever.
+            self._got_value.set()
+
+    async def stop(self) -> None:
+        """Stop the cache.
+
+        The receiver is closed, but the latest value received so far can still be
+        retrieved after stopping.
+        """
+        if not self._task.done():
+            self._task.cancel()
+        try:
+            await self._task
+        except asyncio.CancelledError:
+            pass
+
+    def __repr__(self) -> str:
+        """Return a string representation of this cache."""
+        return (
+            f"<LatestValueCache latest_value={self._latest_value!r}, "
+            f"receiver={self._receiver!r}, unique_id={self._unique_id!r}>"
+        )
@@ -0,0 +1,28 @@
+# License: MIT
+# Copyright © 2024 Frequenz Energy-as-a-Service GmbH
+
+"""A base class for objects that can't be pickled."""
+
+from typing import NoReturn
+
+
+class NotPicklable:
+    """A base class that prevents channels from being pickled.
+
+    Channels are process-local, so a pickled copy would be disconnected from the
+    original channel and messages would be silently lost. Senders and receivers hold
+    a reference to their channel, so they can't be pickled either.
+    """
+
+    def __reduce__(self) -> NoReturn:
+        """Prevent this object from being pickled.
+
+        Raises:
+            TypeError: Always.
+        """
+        raise TypeError(
+            f"{self} can't be pickled: channels, and their senders and receivers, "
+            "can only be used in the process where they were created. To "
+            "communicate with other processes use inter-process communication "
+            "tools, like a multiprocessing.Queue, instead."
+        )
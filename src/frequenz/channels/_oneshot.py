@@ -0,0 +1,306 @@
+# License: MIT
+# Copyright © 2024 Frequenz Energy-as-a-Service GmbH
+
+"""A channel that can send a single message."""
+
+from __future__ import annotations
+
+import asyncio
+from typing import Generic, TypeVar
+
+from typing_extensions import override
+
+from ._exceptions import ChannelClosedError
+from ._generic import ChannelMessageT
+from ._not_picklable import NotPicklable
+from ._receiver import Receiver, ReceiverStoppedError
+from ._sender import Sender, SenderError
+
+
+class _Empty:
+    """A sentinel to indicate that a message has not been set."""
+
+
+class OneshotChannel(NotPicklable, Generic[ChannelMessageT]):
+    """A channel that can send a single message from one sender to one receiver.
+
+    # Description
+
+    [Oneshot][frequenz.channels.OneshotChannel] channels have exactly one
+    [sender][frequenz.channels.Sender] and one [receiver][frequenz.channels.Receiver],
+    available through the [`sender`][frequenz.channels.OneshotChannel.sender] and
+    [`receiver`][frequenz.channels.OneshotChannel.receiver] properties.
+
+    !!! Note inline end "Characteristics"
+
+        * **Buffered:** Yes, for one message
+        * **Buffer full policy:** Raise an exception
+        * **Multiple receivers:** No
+        * **Multiple senders:** No
+        * **Thread-safe:** No
+
+    The sender can send only one message. Sending the message closes the channel, so
+    any further attempts to [`send()`][frequenz.channels.Sender.send] will raise
+    a [`SenderError`][frequenz.channels.SenderError].
+
+    The receiver will receive the message once, and subsequent
+    [`receive()`][frequenz.channels.Receiver.receive] calls will raise
+    a [`ReceiverStoppedError`][frequenz.channels.ReceiverStoppedError].
+
+    If the channel is [closed][frequenz.channels.OneshotChannel.close] before a
+    message is sent, the receiver will stop without receiving anything.
+
+    This channel is useful, for example, to send back a response to a request, where
+    the request includes the sender to use for the reply.
+
+    # Examples
+
+    Example: Send a response to a request
+        ```python
+        import asyncio
+
+        from frequenz.channels import OneshotChannel, Sender
+
+
+        async def handle_request(request: int, reply_to: Sender[int]) -> None:
+            await reply_to.send(request * 2)
+
+
+        async def main() -> None:
+            channel = OneshotChannel[int](name="response")
+
+            async with asyncio.TaskGroup() as task_group:
+                task_group.create_task(handle_request(21, channel.sender))
+                print(f"received {await channel.receiver.receive()}")
+
+
+        asyncio.run(main())
+        ```
+
+        The output should be:
+
+        ```
+        received 42
+        ```
+    """
+
+    def __init__(self, *, name: str) -> None:
+        """Initialize this channel.
+
+        Args:
+            name: The name of the channel. This is for logging purposes, and it will be
+                shown in the string representation of the channel.
+        """
+        self._name: str = name
+        """The name of the channel.
+
+        This is for logging purposes, and it will be shown in the string representation
+        of the channel.
+        """
+
+        self._message: ChannelMessageT | type[_Empty] = _Empty
+        """The message sent to the channel, if any."""
+
+        self._event: asyncio.Event = asyncio.Event()
+        """The event to signal that the channel was either sent to or closed."""
+
+        self._closed: bool = False
+        """Whether the channel is closed."""
+
+        self._sender: _Sender[ChannelMessageT] = _Sender(self)
+        """The only sender of this channel."""
+
+        self._receiver: _Receiver[ChannelMessageT] = _Receiver(self)
+        """The only receiver of this channel."""
+
+    @property
+    def name(self) -> str:
+        """The name of this channel.
+
+        This is for debugging purposes, it will be shown in the string representation
+        of this channel.
+        """
+        return self._name
+
+    @property
+    def is_closed(self) -> bool:
+        """Whether this channel is closed.
+
+        The channel is closed after a message is sent, or after it was explicitly
+        closed with [`close()`][frequenz.channels.OneshotChannel.close].
+        """
+        return self._closed
+
+    @property
+    def sender(self) -> Sender[ChannelMessageT]:
+        """The sender of this channel."""
+        return self._sender
+
+    @property
+    def receiver(self) -> Receiver[ChannelMessageT]:
+        """The receiver of this channel."""
+        return self._receiver
+
+    async def close(self) -> None:
+        """Close this channel.
+
+        Any further attempts to [send()][frequenz.channels.Sender.send] data
+        will raise a [`SenderError`][frequenz.channels.SenderError].
+
+        If a message was already sent, the receiver will still be able to receive
+        it, otherwise [receive()][frequenz.channels.Receiver.receive] calls will
+        raise a [`ReceiverStoppedError`][frequenz.channels.ReceiverStoppedError].
+        """
+        self._close()
+
+    def _close(self) -> None:
+        """Close this channel without waiting.
+
+        This is also used by the sender and receiver, which need to close the channel
+        from non-async code.
+        """
+        self._closed = True
+        self._event.set()
+
+    def __str__(self) -> str:
+        """Return a string representation of this channel."""
+        return f"{type(self).__name__}:{self._name}"
+
+    def __repr__(self) -> str:
+        """Return a string representation of this channel."""
+        return (
+            f"{type(self).__name__}(name={self._name!r}):<"
+            f"sent={self._message is not _Empty!r}, closed={self._closed!r}>"
+        )
+
+
+_T = TypeVar("_T")
+
+
+class _Sender(Sender[_T]):
+    """The sender of a oneshot channel.
+
+    Should not be created directly, but obtained through the
+    [OneshotChannel.sender][frequenz.channels.OneshotChannel.sender] property.
+    """
+
+    def __init__(self, channel: OneshotChannel[_T], /) -> None:
+        """Initialize this sender.
+
+        Args:
+            channel: A reference to the channel that this sender belongs to.
+        """
+        self._channel: OneshotChannel[_T] = channel
+        """The channel that this sender belongs to."""
+
+    @override
+    async def send(self, message: _T, /) -> None:
+        """Send the message across the channel and close it.
+
+        Args:
+            message: The message to be sent.
+
+        Raises:
+            SenderError: If a message was already sent or the channel was closed.
+                A [ChannelClosedError][frequenz.channels.ChannelClosedError] is
+                set as the cause.
+        """
+        # pylint: disable=protected-access
+        if self._channel._closed:
+            raise SenderError("The channel was closed", self) from ChannelClosedError(
+                self._channel
+            )
+        self._channel._message = message
+        self._channel._close()
+        # pylint: enable=protected-access
+
+    def __str__(self) -> str:
+        """Return a string representation of this sender."""
+        return f"{self._channel}:{type(self).__name__}"
+
+    def __repr__(self) -> str:
+        """Return a string representation of this sender."""
+        return f"{type(self).__name__}({self._channel!r})"
+
+
+class _Receiver(Receiver[_T]):
+    """The receiver of a oneshot channel.
+
+    Should not be created directly, but obtained through the
+    [OneshotChannel.receiver][frequenz.channels.OneshotChannel.receiver] property.
+    """
+
+    def __init__(self, channel: OneshotChannel[_T], /) -> None:
+        """Initialize this receiver.
+
+        Args:
+            channel: A reference to the channel that this receiver belongs to.
+        """
+        self._channel: OneshotChannel[_T] = channel
+        """The channel that this receiver belongs to."""
+
+        self._consumed: bool = False
+        """Whether the message was already consumed or the receiver was closed."""
+
+    @override
+    async def ready(self) -> bool:
+        """Wait until the receiver is ready with a message or an error.
+
+        Once a call to `ready()` has finished, the message should be read with
+        a call to `consume()` (`receive()` or iterated over). The receiver will
+        remain ready (this method will return immediately) until it is
+        consumed.
+
+        Returns:
+            Whether the receiver is still active.
+        """
+        if self._consumed:
+            return False
+        await self._channel._event.wait()  # pylint: disable=protected-access
+        return self._channel._message is not _Empty  # pylint: disable=protected-access
+
+    @override
+    def consume(self) -> _T:
+        """Return the message once `ready()` is complete.
+
+        Returns:
+            The message that was sent to the channel.
+
+        Raises:
+            ReceiverStoppedError: If the message was already consumed, or the channel
+                was closed without a message being sent.
+        """
+        # pylint: disable=protected-access
+        if self._consumed:
+            raise ReceiverStoppedError(self)
+        if self._channel._message is _Empty and self._channel._closed:
+            raise ReceiverStoppedError(self) from ChannelClosedError(self._channel)
+
+        assert (
+            self._channel._message is not _Empty
+        ), "`consume()` must be preceded by a call to `ready()`"
+        # mypy doesn't understand that the assert above ensures that the message is
+        # not _Empty.  So we have to use a type ignore here.
+        message: _T = self._channel._message  # type: ignore[assignment]
+        self._channel._message = _Empty
+        self._consumed = True
+        # pylint: enable=protected-access
+        return message
+
+    @override
+    def close(self) -> None:
+        """Close this receiver.
+
+        After closing, the receiver will not be able to receive the message, and no
+        message can be sent to the channel anymore.
+        """
+        self._consumed = True
+        self._channel._close()  # pylint: disable=protected-access
+
+    def __str__(self) -> str:
+        """Return a string representation of this receiver."""
+        return f"{self._channel}:{type(self).__name__}"
+
+    def __repr__(self) -> str:
+        """Return a string representation of this receiver."""
+        return f"{type(self).__name__}({self._channel!r})"
@@ -0,0 +1,62 @@
+# License: MIT
+# Copyright © 2024 Frequenz Energy-as-a-Service GmbH
+
+"""A helper to wake up a pending `ready()` call when its receiver is closed."""
+
+from __future__ import annotations
+
+import asyncio
+from collections.abc import Iterator
+from contextlib import contextmanager
+
+
+class ReadyWaker:
+    """Wakes up a receiver waiting on a condition when the receiver is closed.
+
+    Waiters can only be notified while holding the condition's lock, which can't
+    be acquired from the sync `close()` method of receivers, so this is done in
+    a task.
+    """
+
+    def __init__(self, condition: asyncio.Condition) -> None:
+        """Initialize this waker.
+
+        Args:
+            condition: The condition the receiver waits on for new messages.
+        """
+        self._condition: asyncio.Condition = condition
+        """The condition the receiver waits on for new messages."""
+
+        self._loop: asyncio.AbstractEventLoop | None = None
+        """The loop running the pending `ready()` call, if there is one."""
+
+        self._task: asyncio.Task[None] | None = None
+        """The task waking up the pending `ready()` call."""
+
+    @contextmanager
+    def waiting(self) -> Iterator[None]:
+        """Mark the receiver as waiting on the condition while in this context.
+
+        Yields:
+            Nothing, the receiver should wait on the condition inside the context.
+        """
+        self._loop = asyncio.get_running_loop()
+        try:
+            yield
+        finally:
+            self._loop = None
+
+    def wake_up(self) -> None:
+        """Wake up the pending `ready()` call, if there is one.
+
+        This works even if the loop running the pending call is not running at the
+        moment, the call will be woken up once the loop runs again.
+        """
+        if self._loop is None or self._loop.is_closed():
+            return
+        self._task = self._loop.create_task(self._notify_all())
+
+    async def _notify_all(self) -> None:
+        """Wake up all the receivers waiting on the condition."""
+        async with self._condition:
+            self._condition.notify_all()
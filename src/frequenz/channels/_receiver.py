@@ -123,6 +123,26 @@ except ReceiverError as error:
 # If we get here, the receiver was stopped
 ```
 
+# Sharing Receivers
+
+A receiver is meant to be used by only one consumer (task) at a time. Still, when
+multiple tasks call [`receive()`][frequenz.channels.Receiver.receive] (or iterate)
+on the same receiver concurrently, each message is received by only one of them,
+and none of the calls fail because of the others. There are no guarantees about
+which task gets each message, nor about the order in which waiting tasks are
+served.
+
+This doesn't apply to separate [`ready()`][frequenz.channels.Receiver.ready] and
+[`consume()`][frequenz.channels.Receiver.consume] calls: if another task consumes
+the message between them, `consume()` is called without a message being ready,
+which breaks the receiver contract.
+
+If multiple consumers need to receive messages concurrently, create one receiver
+for each of them instead: receivers of an [`Anycast`][frequenz.channels.Anycast]
+channel to distribute the messages among the consumers, or receivers of
+a [`Broadcast`][frequenz.channels.Broadcast] channel so every consumer gets every
+message.
+
 # Advanced Usage
 
 !!! Warning inline end
@@ -149,12 +169,19 @@ call to [`ready()`][frequenz.channels.Receiver.ready].
 Exceptions are never raised by [`ready()`][frequenz.channels.Receiver.ready], they
 are always delayed until [`consume()`][frequenz.channels.Receiver.consume] is
 called.
+
+Receivers must not lose messages if a call to
+[`ready()`][frequenz.channels.Receiver.ready] is cancelled (for example, because of
+a timeout), so a message is only handed out to the user by
+[`consume()`][frequenz.channels.Receiver.consume], and it will still be available
+in the next call to [`ready()`][frequenz.channels.Receiver.ready].
 """
 
 from __future__ import annotations
 
 from abc import ABC, abstractmethod
 from collections.abc import Callable
+from types import TracebackType
 from typing import TYPE_CHECKING, Any, Generic, Self, TypeGuard, TypeVar, overload
 
 from typing_extensions import override
@@ -234,6 +261,32 @@ class Receiver(ABC, Generic[ReceiverMessageT_co]):
         """
         return self
 
+    async def __aenter__(self) -> Self:
+        """Enter an async context for this receiver.
+
+        The receiver will be [closed][frequenz.channels.Receiver.close] when the
+        context is exited, even if an exception was raised.
+
+        Returns:
+            This receiver.
+        """
+        return self
+
+    async def __aexit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_val: BaseException | None,
+        exc_tb: TracebackType | None,
+    ) -> None:
+        """Exit the async context for this receiver, closing it.
+
+        Args:
+            exc_type: The type of the exception raised in the context, if any.
+            exc_val: The exception raised in the context, if any.
+            exc_tb: The traceback of the exception raised in the context, if any.
+        """
+        self.close()
+
     # We need the noqa here because ReceiverError can be raised by consume()
     # implementations.
     async def receive(self) -> ReceiverMessageT_co:  # noqa: DOC503
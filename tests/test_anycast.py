@@ -147,6 +147,31 @@ async def test_anycast_full() -> None:
         assert False
 
 
+async def test_anycast_invalid_limit() -> None:
+    """Ensure the channel limit must be positive."""
+    assert Anycast[int](name="test", limit=300).limit == 300
+
+    with pytest.raises(ValueError, match="The channel limit must be at least 1"):
+        Anycast[int](name="test", limit=0)
+
+
+async def test_anycast_len() -> None:
+    """Ensure the channel length reflects the messages in the buffer."""
+    acast: Anycast[int] = Anycast(name="test", limit=5)
+
+    receiver = acast.new_receiver()
+    sender = acast.new_sender()
+    assert len(acast) == 0
+    assert acast
+
+    await sender.send(1)
+    await sender.send(2)
+    assert len(acast) == 2
+
+    assert await receiver.receive() == 1
+    assert len(acast) == 1
+
+
 async def test_anycast_none_messages() -> None:
     """Ensure None messages can be sent and received."""
     acast: Anycast[int | None] = Anycast(name="test")
@@ -187,6 +212,101 @@ async def test_anycast_async_iterator() -> None:
     await sender_task
 
 
+async def test_anycast_async_iterator_after_close() -> None:
+    """Ensure iteration stops cleanly and stays stopped after the channel closes."""
+    acast: Anycast[int] = Anycast(name="test")
+
+    sender = acast.new_sender()
+    receiver = acast.new_receiver()
+
+    for val in range(3):
+        await sender.send(val)
+    await acast.close()
+
+    assert [message async for message in receiver] == [0, 1, 2]
+
+    for _ in range(3):
+        with pytest.raises(StopAsyncIteration):
+            await anext(receiver)
+
+
+async def test_anycast_ready_cancellation() -> None:
+    """Ensure cancelling a pending `ready()` doesn't lose any message."""
+    chan = Anycast[int](name="cancel_test")
+    sender = chan.new_sender()
+    receiver = chan.new_receiver()
+
+    ready_task = asyncio.create_task(receiver.ready())
+    await asyncio.sleep(0.1)
+    assert not ready_task.done()
+
+    # The task gets woken up by the message, but is cancelled before it can run.
+    await sender.send(1)
+    ready_task.cancel()
+    with pytest.raises(asyncio.CancelledError):
+        await ready_task
+
+    assert await receiver.receive() == 1
+
+
+async def test_anycast_ready_cancellation_other_receiver() -> None:
+    """Ensure a cancelled `ready()` passes the message on to other waiting receivers."""
+    chan = Anycast[int](name="cancel_test")
+    sender = chan.new_sender()
+    receiver1 = chan.new_receiver()
+    receiver2 = chan.new_receiver()
+
+    ready_task = asyncio.create_task(receiver1.ready())
+    await asyncio.sleep(0.1)
+    receive_task = asyncio.create_task(receiver2.receive())
+    await asyncio.sleep(0.1)
+
+    # Only the first waiter gets woken up, but is cancelled before it can run.
+    await sender.send(1)
+    ready_task.cancel()
+    with pytest.raises(asyncio.CancelledError):
+        await ready_task
+
+    assert await asyncio.wait_for(receive_task, 1) == 1
+    assert len(chan) == 0
+
+
+async def test_anycast_send_cancellation_other_sender() -> None:
+    """Ensure a cancelled `send()` passes the free space on to other waiting senders."""
+    chan = Anycast[int](name="cancel_test", limit=1)
+    sender = chan.new_sender()
+    receiver = chan.new_receiver()
+
+    await sender.send(1)
+    send_task1 = asyncio.create_task(sender.send(2))
+    await asyncio.sleep(0.1)
+    send_task2 = asyncio.create_task(sender.send(3))
+    await asyncio.sleep(0.1)
+
+    # Only the first waiter gets woken up, but is cancelled before it can run.
+    assert await receiver.receive() == 1
+    send_task1.cancel()
+    with pytest.raises(asyncio.CancelledError):
+        await send_task1
+
+    await asyncio.wait_for(send_task2, 1)
+    assert len(chan) == 1
+    assert await receiver.receive() == 3
+
+
+async def test_anycast_receive_timeout() -> None:
+    """Ensure a message sent after a receive timed out is still received."""
+    chan = Anycast[int](name="cancel_test")
+    sender = chan.new_sender()
+    receiver = chan.new_receiver()
+
+    with pytest.raises(asyncio.TimeoutError):
+        await asyncio.wait_for(receiver.receive(), 0.1)
+
+    await sender.send(2)
+    assert await receiver.receive() == 2
+
+
 async def test_anycast_map() -> None:
     """Ensure map runs on all incoming messages."""
     chan: Anycast[int] = Anycast(name="test")
@@ -219,6 +339,44 @@ async def test_anycast_filter() -> None:
     assert (await receiver.receive()) == 15
 
 
+async def test_anycast_closed_receiver_takes_no_messages() -> None:
+    """Ensure closed receivers leave the messages to the other receivers."""
+    acast: Anycast[int] = Anycast(name="test")
+
+    sender = acast.new_sender()
+    receiver1 = acast.new_receiver()
+    receiver2 = acast.new_receiver()
+
+    receiver1.close()
+    await sender.send(1)
+
+    with pytest.raises(ReceiverStoppedError) as excinfo:
+        await receiver1.receive()
+    assert excinfo.value.receiver is receiver1
+
+    assert await receiver2.receive() == 1
+
+
+async def test_anycast_close_pending_receiver() -> None:
+    """Ensure closing a receiver stops a pending `receive()` right away."""
+    acast: Anycast[int] = Anycast(name="test")
+
+    sender = acast.new_sender()
+    receiver1 = acast.new_receiver()
+    receiver2 = acast.new_receiver()
+
+    receive_task = asyncio.create_task(receiver1.receive())
+    await asyncio.sleep(0.1)
+    assert not receive_task.done()
+
+    receiver1.close()
+    with pytest.raises(ReceiverStoppedError):
+        await asyncio.wait_for(receive_task, 1)
+
+    await sender.send(1)
+    assert await receiver2.receive() == 1
+
+
 async def test_receiver_close_behavior() -> None:
     """Ensure close() is immediate for anycast channels."""
     acast: Anycast[int] = Anycast(name="close_behavior_test")
@@ -232,7 +390,22 @@ async def test_receiver_close_behavior() -> None:
     with pytest.raises(SenderError):
         await sender.send(2)
 
-    assert await receiver.receive() == 1
-
+    # The message in the shared buffer is left to other receivers.
     with pytest.raises(ReceiverStoppedError):
         await receiver.receive()
+    assert len(acast) == 1
+
+
+async def test_anycast_shared_receiver() -> None:
+    """Ensure concurrent `receive()` calls on one receiver get different messages."""
+    chan = Anycast[int](name="shared_test")
+    sender = chan.new_sender()
+    receiver = chan.new_receiver()
+
+    receive_tasks = [asyncio.create_task(receiver.receive()) for _ in range(2)]
+    await asyncio.sleep(0.1)
+
+    await sender.send(1)
+    await sender.send(2)
+    received = await asyncio.wait_for(asyncio.gather(*receive_tasks), 1)
+    assert sorted(received) == [1, 2]
@@ -106,10 +106,6 @@ async def test_broadcast_after_close() -> None:
 
 async def test_broadcast_overflow() -> None:
     """Ensure messages sent to full broadcast receivers get dropped."""
-    from frequenz.channels._broadcast import (  # pylint: disable=import-outside-toplevel
-        _Receiver,
-    )
-
     bcast: Broadcast[int] = Broadcast(name="meter_5")
 
     big_recv_size = 10
@@ -117,9 +113,7 @@ async def test_broadcast_overflow() -> None:
     sender = bcast.new_sender()
 
     big_receiver = bcast.new_receiver(name="named-recv", limit=big_recv_size)
-    assert isinstance(big_receiver, _Receiver)
     small_receiver = bcast.new_receiver(limit=small_recv_size)
-    assert isinstance(small_receiver, _Receiver)
 
     async def drain_receivers() -> tuple[int, int]:
         big_sum = 0
@@ -161,6 +155,17 @@ async def test_broadcast_overflow() -> None:
     )
 
 
+async def test_broadcast_receiver_limit() -> None:
+    """Ensure the receiver limit is validated and exposed."""
+    bcast: Broadcast[int] = Broadcast(name="meter_5")
+
+    receiver = bcast.new_receiver(limit=300)
+    assert receiver.limit == 300
+
+    with pytest.raises(ValueError, match="The receiver limit must be at least 1"):
+        bcast.new_receiver(limit=0)
+
+
 async def test_broadcast_resend_latest() -> None:
     """Check if new receivers get the latest message when resend_latest is set."""
     bcast: Broadcast[int] = Broadcast(name="new_recv_test", resend_latest=True)
@@ -217,6 +222,56 @@ async def test_broadcast_async_iterator() -> None:
     await sender_task
 
 
+async def test_broadcast_async_iterator_after_close() -> None:
+    """Ensure iteration stops cleanly and stays stopped after the channel closes."""
+    bcast: Broadcast[int] = Broadcast(name="iter_test")
+
+    sender = bcast.new_sender()
+    receiver = bcast.new_receiver()
+
+    for val in range(3):
+        await sender.send(val)
+    await bcast.close()
+
+    assert [message async for message in receiver] == [0, 1, 2]
+
+    for _ in range(3):
+        with pytest.raises(StopAsyncIteration):
+            await anext(receiver)
+
+
+async def test_broadcast_ready_cancellation() -> None:
+    """Ensure cancelling a pending `ready()` doesn't lose any message."""
+    chan = Broadcast[int](name="cancel_test")
+    sender = chan.new_sender()
+    receiver = chan.new_receiver()
+
+    ready_task = asyncio.create_task(receiver.ready())
+    await asyncio.sleep(0.1)
+    assert not ready_task.done()
+
+    # The task gets woken up by the message, but is cancelled before it can run.
+    await sender.send(1)
+    ready_task.cancel()
+    with pytest.raises(asyncio.CancelledError):
+        await ready_task
+
+    assert await receiver.receive() == 1
+
+
+async def test_broadcast_receive_timeout() -> None:
+    """Ensure a message sent after a receive timed out is still received."""
+    chan = Broadcast[int](name="cancel_test")
+    sender = chan.new_sender()
+    receiver = chan.new_receiver()
+
+    with pytest.raises(asyncio.TimeoutError):
+        await asyncio.wait_for(receiver.receive(), 0.1)
+
+    await sender.send(2)
+    assert await receiver.receive() == 2
+
+
 async def test_broadcast_map() -> None:
     """Ensure map runs on all incoming messages."""
     chan = Broadcast[int](name="input-chan")
@@ -298,6 +353,40 @@ async def test_broadcast_receiver_drop() -> None:
     # pylint: enable=protected-access
 
 
+async def test_broadcast_receiver_count() -> None:
+    """Ensure the receiver count only includes live receivers."""
+    chan = Broadcast[int](name="input-chan")
+    assert chan.receiver_count == 0
+
+    receiver1 = chan.new_receiver()
+    receiver2 = chan.new_receiver()
+    assert chan.receiver_count == 2
+
+    receiver1.close()
+    assert chan.receiver_count == 1
+
+    del receiver2
+    assert chan.receiver_count == 0
+
+
+async def test_broadcast_receiver_context_manager() -> None:
+    """Ensure receivers used as async context managers are closed on exit."""
+    chan = Broadcast[int](name="input-chan")
+    sender = chan.new_sender()
+
+    async with chan.new_receiver() as receiver:
+        assert chan.receiver_count == 1
+        await sender.send(10)
+        assert await receiver.receive() == 10
+    assert chan.receiver_count == 0
+
+    with pytest.raises(ValueError, match="error in context"):
+        async with chan.new_receiver():
+            assert chan.receiver_count == 1
+            raise ValueError("error in context")
+    assert chan.receiver_count == 0
+
+
 async def test_type_variance() -> None:
     """Ensure that the type variance of Broadcast is working."""
 
@@ -336,3 +425,66 @@ async def test_broadcast_close_behavior() -> None:
 
     with pytest.raises(ReceiverStoppedError):
         await receiver.receive()
+
+
+async def test_broadcast_closed_receiver_drained() -> None:
+    """Ensure a closed, drained receiver stops even if the channel is still open."""
+    bcast: Broadcast[int] = Broadcast(name="close_behavior_test")
+
+    sender = bcast.new_sender()
+    receiver = bcast.new_receiver()
+    receiver.close()
+
+    await sender.send(1)
+    assert len(receiver) == 0
+    assert not await receiver.ready()
+    with pytest.raises(ReceiverStoppedError) as excinfo:
+        receiver.consume()
+    assert excinfo.value.receiver is receiver
+    assert excinfo.value.__cause__ is None
+
+
+async def test_broadcast_close_pending_receiver() -> None:
+    """Ensure closing a receiver stops a pending `receive()` right away."""
+    bcast: Broadcast[int] = Broadcast(name="close_behavior_test")
+    receiver = bcast.new_receiver()
+
+    receive_task = asyncio.create_task(receiver.receive())
+    await asyncio.sleep(0.1)
+    assert not receive_task.done()
+
+    receiver.close()
+    with pytest.raises(ReceiverStoppedError):
+        await asyncio.wait_for(receive_task, 1)
+
+
+def test_broadcast_close_pending_receiver_without_running_loop() -> None:
+    """Ensure receivers can be closed while the loop of a pending `ready()` is idle."""
+    loop = asyncio.new_event_loop()
+    try:
+        bcast: Broadcast[int] = Broadcast(name="close_behavior_test")
+        receiver = bcast.new_receiver()
+
+        ready_task = loop.create_task(receiver.ready())
+        loop.run_until_complete(asyncio.sleep(0.1))
+        assert not ready_task.done()
+
+        receiver.close()
+        assert loop.run_until_complete(asyncio.wait_for(ready_task, 1)) is False
+    finally:
+        loop.close()
+
+
+async def test_broadcast_shared_receiver() -> None:
+    """Ensure concurrent `receive()` calls on one receiver get different messages."""
+    chan = Broadcast[int](name="shared_test")
+    sender = chan.new_sender()
+    receiver = chan.new_receiver()
+
+    receive_tasks = [asyncio.create_task(receiver.receive()) for _ in range(2)]
+    await asyncio.sleep(0.1)
+
+    await sender.send(1)
+    await sender.send(2)
+    received = await asyncio.wait_for(asyncio.gather(*receive_tasks), 1)
+    assert sorted(received) == [1, 2]
@@ -0,0 +1,99 @@
+# License: MIT
+# Copyright © 2024 Frequenz Energy-as-a-Service GmbH
+
+"""Tests for the LatestValueCache implementation."""
+
+import asyncio
+
+import pytest
+
+from frequenz.channels import Broadcast, LatestValueCache, ReceiverStoppedError
+
+
+async def test_latest_value_cache() -> None:
+    """Ensure LatestValueCache always keeps the latest value."""
+    channel = Broadcast[int](name="lvc_test")
+
+    cache = LatestValueCache(channel.new_receiver())
+    sender = channel.new_sender()
+
+    assert not cache.has_value()
+    with pytest.raises(ValueError, match="No value has been received yet."):
+        cache.get()
+
+    await sender.send(5)
+    assert await cache.wait_for_value() == 5
+    assert cache.has_value()
+    assert cache.get() == 5
+
+    await sender.send(6)
+    await sender.send(7)
+    await sender.send(8)
+    await asyncio.sleep(0)
+
+    assert cache.get() == 8
+    assert await cache.wait_for_value() == 8
+
+    await cache.stop()
+    assert cache.get() == 8
+
+
+async def test_latest_value_cache_wait_for_value() -> None:
+    """Ensure waiting for a value wakes up as soon as one is received."""
+    channel = Broadcast[int](name="lvc_test")
+
+    cache = LatestValueCache(channel.new_receiver())
+    sender = channel.new_sender()
+
+    waiter = asyncio.create_task(cache.wait_for_value())
+    await asyncio.sleep(0.1)
+    assert not waiter.done()
+
+    await sender.send(3)
+    assert await waiter == 3
+
+    await cache.stop()
+
+
+async def test_latest_value_cache_stopped_receiver() -> None:
+    """Ensure waiting for a value fails if the receiver stops without any."""
+    channel = Broadcast[int](name="lvc_test")
+    receiver = channel.new_receiver()
+
+    cache = LatestValueCache(receiver)
+    await channel.close()
+
+    with pytest.raises(ReceiverStoppedError) as excinfo:
+        await cache.wait_for_value()
+    assert excinfo.value.receiver is receiver
+    assert not cache.has_value()
+
+
+async def test_latest_value_cache_stop_closes_receiver() -> None:
+    """Ensure stopping the cache closes its receiver."""
+    channel = Broadcast[int](name="lvc_test")
+
+    cache = LatestValueCache(channel.new_receiver())
+    await asyncio.sleep(0)
+    assert channel.receiver_count == 1
+
+    await cache.stop()
+    assert channel.receiver_count == 0
+
+
+async def test_latest_value_cache_failed_receiver() -> None:
+    """Ensure errors raised by the receiver are reported as the stop cause."""
+    channel = Broadcast[int](name="lvc_test")
+
+    def fail(value: int) -> int:
+        raise KeyError(value)
+
+    cache = LatestValueCache(channel.new_receiver().map(fail))
+    await channel.new_sender().send(1)
+
+    with pytest.raises(ReceiverStoppedError) as excinfo:
+        await cache.wait_for_value()
+    assert isinstance(excinfo.value.__cause__, KeyError)
+
+    await cache.stop()
+    assert channel.receiver_count == 0
@@ -0,0 +1,36 @@
+# License: MIT
+# Copyright © 2024 Frequenz Energy-as-a-Service GmbH
+
+"""Tests for channels not being picklable."""
+
+import pickle
+from collections.abc import Callable
+
+import pytest
+
+from frequenz.channels import Anycast, Broadcast, OneshotChannel
+
+
+def _anycast_objects() -> tuple[object, ...]:
+    chan = Anycast[int](name="input-chan")
+    return chan, chan.new_sender(), chan.new_receiver()
+
+
+def _broadcast_objects() -> tuple[object, ...]:
+    chan = Broadcast[int](name="input-chan")
+    return chan, chan.new_sender(), chan.new_receiver()
+
+
+def _oneshot_objects() -> tuple[object, ...]:
+    chan = OneshotChannel[int](name="input-chan")
+    return chan, chan.sender, chan.receiver
+
+
+@pytest.mark.parametrize(
+    "make_objects", [_anycast_objects, _broadcast_objects, _oneshot_objects]
+)
+async def test_pickle(make_objects: Callable[[], tuple[object, ...]]) -> None:
+    """Ensure pickling channels, senders or receivers fails with a clear error."""
+    for obj in make_objects():
+        with pytest.raises(TypeError, match="can only be used in the process"):
+            pickle.dumps(obj)
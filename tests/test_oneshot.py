@@ -0,0 +1,94 @@
+# License: MIT
+# Copyright © 2024 Frequenz Energy-as-a-Service GmbH
+
+"""Tests for the OneshotChannel implementation."""
+
+
+import asyncio
+
+import pytest
+
+from frequenz.channels import (
+    ChannelClosedError,
+    OneshotChannel,
+    ReceiverStoppedError,
+    SenderError,
+)
+
+
+async def test_oneshot() -> None:
+    """Ensure the message is received exactly once."""
+    channel = OneshotChannel[int](name="test")
+
+    async def send() -> None:
+        await asyncio.sleep(0.1)
+        await channel.sender.send(42)
+
+    sender_task = asyncio.create_task(send())
+    assert await channel.receiver.receive() == 42
+    await sender_task
+
+    assert channel.is_closed
+    with pytest.raises(ReceiverStoppedError) as excinfo:
+        await channel.receiver.receive()
+    assert excinfo.value.receiver is channel.receiver
+
+
+async def test_oneshot_send_twice() -> None:
+    """Ensure only one message can be sent."""
+    channel = OneshotChannel[int](name="test")
+
+    await channel.sender.send(1)
+    with pytest.raises(SenderError) as excinfo:
+        await channel.sender.send(2)
+    assert isinstance(excinfo.value.__cause__, ChannelClosedError)
+    assert excinfo.value.__cause__.channel is channel
+
+    assert await channel.receiver.receive() == 1
+
+
+async def test_oneshot_none_message() -> None:
+    """Ensure None can be sent and received."""
+    channel = OneshotChannel[int | None](name="test")
+
+    await channel.sender.send(None)
+    assert await channel.receiver.receive() is None
+
+
+async def test_oneshot_close_before_send() -> None:
+    """Ensure the receiver stops if the channel is closed without a message."""
+    channel = OneshotChannel[int](name="test")
+
+    receiver_task = asyncio.create_task(channel.receiver.receive())
+    await asyncio.sleep(0.1)
+    await channel.close()
+
+    with pytest.raises(ReceiverStoppedError) as excinfo:
+        await receiver_task
+    assert isinstance(excinfo.value.__cause__, ChannelClosedError)
+
+    with pytest.raises(SenderError):
+        await channel.sender.send(1)
+
+
+async def test_oneshot_async_iterator() -> None:
+    """Ensure the receiver works as an async iterator."""
+    channel = OneshotChannel[str](name="test")
+
+    await channel.sender.send("one")
+
+    received = [message async for message in channel.receiver]
+    assert received == ["one"]
+
+
+async def test_oneshot_receiver_close() -> None:
+    """Ensure closing the receiver closes the channel."""
+    channel = OneshotChannel[int](name="test")
+
+    channel.receiver.close()
+    assert channel.is_closed
+
+    with pytest.raises(SenderError):
+        await channel.sender.send(1)
+    with pytest.raises(ReceiverStoppedError):
+        await channel.receiver.receive()